@@ -0,0 +1,90 @@
+use crate::resource::Rehydrate;
+use base64::{decode, encode, DecodeError};
+use core::marker::PhantomData;
+use core_error::Error;
+use futures::{
+    future::{ready, Either, Map, MapErr, Ready},
+    FutureExt, TryFutureExt,
+};
+use thiserror::Error;
+
+pub struct Base64<U>(PhantomData<U>);
+
+#[derive(Debug, Error)]
+#[bounds(where T: Error + 'static)]
+pub enum Base64Error<T> {
+    #[error("base64 error: {0}")]
+    Base64(#[source] DecodeError),
+    #[error("inner error: {0}")]
+    Inner(#[source] T),
+}
+
+impl<T, U: Rehydrate<T>> Rehydrate<T> for Base64<U> {
+    type RehydrateError = Base64Error<U::RehydrateError>;
+    type Rehydrate = Either<
+        MapErr<U::Rehydrate, fn(U::RehydrateError) -> Base64Error<U::RehydrateError>>,
+        Ready<Result<T, Base64Error<U::RehydrateError>>>,
+    >;
+    type DumpError = U::DumpError;
+    type Dump = Map<U::Dump, fn(Result<Vec<u8>, U::DumpError>) -> Result<Vec<u8>, U::DumpError>>;
+
+    fn rehydrate(data: Vec<u8>) -> Self::Rehydrate {
+        match decode(&data) {
+            Ok(data) => Either::Left(U::rehydrate(data).map_err(
+                Base64Error::Inner as fn(U::RehydrateError) -> Base64Error<U::RehydrateError>,
+            )),
+            Err(e) => Either::Right(ready(Err(Base64Error::Base64(e)))),
+        }
+    }
+
+    fn dump(data: T) -> Self::Dump {
+        U::dump(data).map(
+            (|data: Result<Vec<u8>, U::DumpError>| data.map(|data| encode(&data).into_bytes()))
+                as fn(Result<Vec<u8>, U::DumpError>) -> Result<Vec<u8>, U::DumpError>,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cbor;
+    use futures::executor::block_on;
+
+    #[test]
+    fn round_trip() {
+        let item = vec![1u32, 2, 3, 4];
+
+        let data = block_on(<Base64<Cbor> as Rehydrate<Vec<u32>>>::dump(item.clone())).unwrap();
+        let rehydrated = block_on(<Base64<Cbor> as Rehydrate<Vec<u32>>>::rehydrate(data)).unwrap();
+
+        assert_eq!(rehydrated, item);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn round_trip_gzip() {
+        use crate::Gzip;
+
+        let item = vec![1u32, 2, 3, 4];
+
+        let data = block_on(<Base64<Gzip<Cbor>> as Rehydrate<Vec<u32>>>::dump(
+            item.clone(),
+        ))
+        .unwrap();
+        let rehydrated =
+            block_on(<Base64<Gzip<Cbor>> as Rehydrate<Vec<u32>>>::rehydrate(data)).unwrap();
+
+        assert_eq!(rehydrated, item);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            block_on(<Base64<Cbor> as Rehydrate<Vec<u32>>>::rehydrate(
+                b"!!!".to_vec()
+            )),
+            Err(Base64Error::Base64(_))
+        ));
+    }
+}
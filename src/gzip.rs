@@ -0,0 +1,176 @@
+use crate::resource::Rehydrate;
+use core::marker::PhantomData;
+use core_error::Error;
+use flate2::{bufread::GzDecoder, write::GzEncoder, Compression};
+use futures::{
+    future::{ready, Either, Map, MapErr, Ready},
+    FutureExt, TryFutureExt,
+};
+use std::io::{self, Read, Write};
+use thiserror::Error;
+
+pub trait Level {
+    const LEVEL: u32;
+}
+
+pub struct Fast;
+
+impl Level for Fast {
+    const LEVEL: u32 = 1;
+}
+
+pub struct Balanced;
+
+impl Level for Balanced {
+    const LEVEL: u32 = 6;
+}
+
+pub struct Best;
+
+impl Level for Best {
+    const LEVEL: u32 = 9;
+}
+
+/// Upper bound on uncompressed data size, checked on both dump and rehydrate.
+pub const MAX_INFLATED_LEN: u64 = 64 * 1024 * 1024;
+
+/// Data must be a single gzip member; anything following it is rejected as trailing data.
+pub struct Gzip<U, L: Level = Balanced>(PhantomData<(U, L)>);
+
+#[derive(Debug, Error)]
+#[bounds(where T: Error + 'static)]
+pub enum GzipError<T> {
+    #[error("gzip error: {0}")]
+    Gzip(#[source] io::Error),
+    #[error("inner error: {0}")]
+    Inner(#[source] T),
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl<T, U: Rehydrate<T>, L: Level> Rehydrate<T> for Gzip<U, L> {
+    type RehydrateError = GzipError<U::RehydrateError>;
+    type Rehydrate = Either<
+        MapErr<U::Rehydrate, fn(U::RehydrateError) -> GzipError<U::RehydrateError>>,
+        Ready<Result<T, GzipError<U::RehydrateError>>>,
+    >;
+    type DumpError = GzipError<U::DumpError>;
+    type Dump =
+        Map<U::Dump, fn(Result<Vec<u8>, U::DumpError>) -> Result<Vec<u8>, GzipError<U::DumpError>>>;
+
+    fn rehydrate(data: Vec<u8>) -> Self::Rehydrate {
+        let mut input = &*data;
+        let mut buffer = vec![];
+
+        let read = GzDecoder::new(&mut input)
+            .take(MAX_INFLATED_LEN + 1)
+            .read_to_end(&mut buffer);
+
+        match read {
+            Ok(len) if len as u64 > MAX_INFLATED_LEN => Either::Right(ready(Err(GzipError::Gzip(
+                invalid_data("inflated data exceeds size limit"),
+            )))),
+            Ok(_) if !input.is_empty() => Either::Right(ready(Err(GzipError::Gzip(invalid_data(
+                "trailing data after gzip stream",
+            ))))),
+            Ok(_) => Either::Left(U::rehydrate(buffer).map_err(
+                GzipError::Inner as fn(U::RehydrateError) -> GzipError<U::RehydrateError>,
+            )),
+            Err(e) => Either::Right(ready(Err(GzipError::Gzip(e)))),
+        }
+    }
+
+    fn dump(data: T) -> Self::Dump {
+        U::dump(data).map(
+            (|data: Result<Vec<u8>, U::DumpError>| -> Result<Vec<u8>, GzipError<U::DumpError>> {
+                let data = data.map_err(GzipError::Inner)?;
+                if data.len() as u64 > MAX_INFLATED_LEN {
+                    return Err(GzipError::Gzip(invalid_data("data exceeds size limit")));
+                }
+                let mut encoder = GzEncoder::new(vec![], Compression::new(L::LEVEL));
+                encoder.write_all(&data).map_err(GzipError::Gzip)?;
+                encoder.finish().map_err(GzipError::Gzip)
+            })
+                as fn(Result<Vec<u8>, U::DumpError>) -> Result<Vec<u8>, GzipError<U::DumpError>>,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cbor, Convert};
+    use futures::executor::block_on;
+    use serde_cbor::Error as CborError;
+
+    fn dump(item: Vec<u32>) -> Vec<u8> {
+        block_on(<Gzip<Cbor> as Rehydrate<Vec<u32>>>::dump(item)).unwrap()
+    }
+
+    fn rehydrate(data: Vec<u8>) -> Result<Vec<u32>, GzipError<CborError>> {
+        block_on(<Gzip<Cbor> as Rehydrate<Vec<u32>>>::rehydrate(data))
+    }
+
+    #[test]
+    fn round_trip() {
+        let item = vec![1, 2, 3, 4];
+
+        assert_eq!(rehydrate(dump(item.clone())).unwrap(), item);
+    }
+
+    #[test]
+    fn garbage() {
+        assert!(matches!(
+            rehydrate(b"not gzip at all".to_vec()),
+            Err(GzipError::Gzip(_))
+        ));
+    }
+
+    #[test]
+    fn truncated() {
+        let mut data = dump(vec![1, 2, 3, 4]);
+        data.truncate(data.len() / 2);
+
+        assert!(matches!(rehydrate(data), Err(GzipError::Gzip(_))));
+    }
+
+    #[test]
+    fn trailing_garbage() {
+        let mut data = dump(vec![1, 2, 3, 4]);
+        data.extend_from_slice(b"garbage");
+
+        assert!(matches!(rehydrate(data), Err(GzipError::Gzip(_))));
+    }
+
+    #[test]
+    fn empty() {
+        assert!(matches!(rehydrate(vec![]), Err(GzipError::Gzip(_))));
+    }
+
+    #[test]
+    fn exceeds_limit() {
+        let mut encoder = GzEncoder::new(vec![], Compression::fast());
+        let chunk = vec![0u8; 1024 * 1024];
+        for _ in 0..(MAX_INFLATED_LEN / chunk.len() as u64) {
+            encoder.write_all(&chunk).unwrap();
+        }
+        encoder.write_all(&[0]).unwrap();
+
+        assert!(matches!(
+            rehydrate(encoder.finish().unwrap()),
+            Err(GzipError::Gzip(_))
+        ));
+    }
+
+    #[test]
+    fn dump_exceeds_limit() {
+        let item = vec![0u8; MAX_INFLATED_LEN as usize + 1];
+
+        assert!(matches!(
+            block_on(<Gzip<Convert> as Rehydrate<Vec<u8>>>::dump(item)),
+            Err(GzipError::Gzip(_))
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "bincode-serde")]
+use bincode::{DefaultOptions, Error as BincodeError, Options};
 use core::{
     any::{Any, TypeId},
     cell::RefCell,
@@ -17,6 +19,8 @@ use futures::{
 use ring::digest::{digest, SHA256};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_cbor::{from_slice, to_vec, Error as CborError};
+#[cfg(feature = "json")]
+use serde_json::Error as JsonError;
 use std::{collections::HashMap, hash::Hash, sync::Arc};
 use thiserror::Error;
 
@@ -43,6 +47,18 @@ pub use memory_store::MemoryStore;
 mod simple_resource_manager;
 pub use simple_resource_manager::SimpleResourceManager;
 
+#[cfg(feature = "gzip")]
+pub mod gzip;
+#[cfg(feature = "gzip")]
+#[doc(inline)]
+pub use gzip::Gzip;
+
+#[cfg(feature = "base64-text")]
+pub mod base64_text;
+#[cfg(feature = "base64-text")]
+#[doc(inline)]
+pub use base64_text::Base64;
+
 use resource::{
     hash::{Algorithm, Hasher},
     Rehydrate,
@@ -108,6 +124,53 @@ impl<T: DeserializeOwned + Serialize> Rehydrate<T> for Cbor {
     }
 }
 
+#[cfg(feature = "json")]
+pub struct Json;
+
+#[cfg(feature = "json")]
+impl<T: DeserializeOwned + Serialize> Rehydrate<T> for Json {
+    type RehydrateError = JsonError;
+    type Rehydrate = Ready<Result<T, Self::RehydrateError>>;
+    type DumpError = JsonError;
+    type Dump = Ready<Result<Vec<u8>, Self::DumpError>>;
+
+    fn rehydrate(data: Vec<u8>) -> Self::Rehydrate {
+        ready(serde_json::from_slice(&data))
+    }
+    fn dump(data: T) -> Self::Dump {
+        ready(serde_json::to_vec(&data))
+    }
+}
+
+/// Bincode is not self-describing, so data can only be rehydrated as the exact type it was dumped from.
+#[cfg(feature = "bincode-serde")]
+pub struct Bincode;
+
+#[cfg(feature = "bincode-serde")]
+impl<T: DeserializeOwned + Serialize> Rehydrate<T> for Bincode {
+    type RehydrateError = BincodeError;
+    type Rehydrate = Ready<Result<T, Self::RehydrateError>>;
+    type DumpError = BincodeError;
+    type Dump = Ready<Result<Vec<u8>, Self::DumpError>>;
+
+    fn rehydrate(data: Vec<u8>) -> Self::Rehydrate {
+        ready(
+            DefaultOptions::new()
+                .with_fixint_encoding()
+                .reject_trailing_bytes()
+                .deserialize(&data),
+        )
+    }
+    fn dump(data: T) -> Self::Dump {
+        ready(
+            DefaultOptions::new()
+                .with_fixint_encoding()
+                .reject_trailing_bytes()
+                .serialize(&data),
+        )
+    }
+}
+
 pub struct Convert;
 
 impl<T: TryFrom<Vec<u8>> + TryInto<Vec<u8>>> Rehydrate<T> for Convert {
@@ -324,3 +387,65 @@ impl<T: Spawn> Spawn for CorePreserver<T> {
             .spawn_obj(Box::pin(CoreTask::new(future, core)).into())
     }
 }
+
+#[cfg(all(test, any(feature = "json", feature = "bincode-serde")))]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip() {
+        let mut item = HashMap::new();
+        item.insert("a".to_owned(), 1u32);
+        item.insert("b".to_owned(), 2u32);
+
+        let data = block_on(<Json as Rehydrate<HashMap<String, u32>>>::dump(
+            item.clone(),
+        ))
+        .unwrap();
+        let rehydrated: HashMap<String, u32> = block_on(Json::rehydrate(data)).unwrap();
+
+        assert_eq!(rehydrated, item);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_malformed() {
+        let rehydrated: Result<HashMap<String, u32>, _> =
+            block_on(Json::rehydrate(b"{\"a\":".to_vec()));
+
+        assert!(rehydrated.is_err());
+    }
+
+    #[cfg(feature = "bincode-serde")]
+    #[test]
+    fn bincode_round_trip() {
+        let item = (42u64, "hello".to_owned());
+
+        let data = block_on(<Bincode as Rehydrate<(u64, String)>>::dump(item.clone())).unwrap();
+        let rehydrated: (u64, String) = block_on(Bincode::rehydrate(data)).unwrap();
+
+        assert_eq!(rehydrated, item);
+    }
+
+    #[cfg(feature = "bincode-serde")]
+    #[test]
+    fn bincode_truncated() {
+        let item = (42u64, "hello".to_owned());
+
+        let data = block_on(<Bincode as Rehydrate<(u64, String)>>::dump(item)).unwrap();
+
+        let mut truncated = data.clone();
+        truncated.pop();
+        let rehydrated: Result<(u64, String), _> = block_on(Bincode::rehydrate(truncated));
+
+        assert!(rehydrated.is_err());
+
+        let mut trailing = data;
+        trailing.extend_from_slice(b"garbage");
+        let rehydrated: Result<(u64, String), _> = block_on(Bincode::rehydrate(trailing));
+
+        assert!(rehydrated.is_err());
+    }
+}